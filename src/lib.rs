@@ -49,21 +49,23 @@
 //! assert_eq!(expr.get_result().unwrap(), 11); // The result is 11
 //! ```
 
-use std::collections::VecDeque;
+use num_bigint::BigInt;
+use num_traits::{Num, Signed, ToPrimitive, Zero};
 use std::sync::Mutex;
 
 #[macro_use]
 extern crate lazy_static;
 
 __lazy_static_internal!(@MAKE TY, ,(pub),FACTORIAL_CACHE);
-__lazy_static_internal!(@TAIL,FACTORIAL_CACHE:Mutex<Vec<i64>>  = Mutex::new(vec![1,1]));
+__lazy_static_internal!(@TAIL,FACTORIAL_CACHE:Mutex<Vec<BigInt>>  = Mutex::new(vec![BigInt::from(1), BigInt::from(1)]));
 lazy_static!();
 
 /// Represents an arithmetic expression, its postfix notation, and computation result.
 pub struct Expression {
     pub expr: String,
     pub post_fix: String,
-    pub result: Result<i64, ErrorKind>,
+    pub result: Result<BigInt, ErrorKind>,
+    pub float_result: Result<f64, ErrorKind>,
 }
 
 /// Enumerates possible errors that can occur during expression parsing and evaluation.
@@ -75,6 +77,10 @@ pub enum ErrorKind {
     Overflow,
     InvalidToken,
     MalformedExpression,
+    NegativeExponent,
+    NonIntegerFactorial,
+    NegativeFactorial,
+    TooLarge,
 }
 
 impl ErrorKind {
@@ -87,10 +93,77 @@ impl ErrorKind {
             ErrorKind::Overflow => "Overflow",
             ErrorKind::InvalidToken => "Invalid token",
             ErrorKind::MalformedExpression => "Malformed postfix expression",
+            ErrorKind::NegativeExponent => "Negative exponent",
+            ErrorKind::NonIntegerFactorial => "Factorial of a non-integer value",
+            ErrorKind::NegativeFactorial => "Factorial of a negative number",
+            ErrorKind::TooLarge => "Operand too large to compute",
         }
     }
 }
 
+/// Describes whether an operator groups with operands to its left or to its right.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Associativity {
+    Left,
+    Right,
+}
+
+/// A node in the abstract syntax tree produced by `Expression::parse_ast`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    Number(String),
+    BinaryOp {
+        op: char,
+        lhs: Box<Node>,
+        rhs: Box<Node>,
+    },
+    UnaryOp {
+        op: char,
+        operand: Box<Node>,
+    },
+}
+
+/// A lexical token produced by `Expression::tokenize`.
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(String),
+    Op(char),
+    Bang,
+    LParen,
+    RParen,
+}
+
+/// Walks a token stream one token at a time, as consumed by the Pratt parser.
+struct TokenCursor {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl TokenCursor {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        if token.is_some() {
+            self.pos += 1;
+        }
+        token
+    }
+}
+
+/// Binding power of the postfix `!` operator, higher than any infix or prefix operator.
+const FACTORIAL_BINDING_POWER: u8 = 200;
+
+/// Largest factorial argument computed before bailing out with `ErrorKind::TooLarge`,
+/// since `BigInt` has no inherent ceiling and larger values take too long to compute.
+const MAX_FACTORIAL_ARG: usize = 10_000;
+
+/// Largest exponent computed before bailing out with `ErrorKind::TooLarge`, for the
+/// same reason.
+const MAX_EXPONENT: u32 = 10_000;
+
 impl std::fmt::Display for ErrorKind {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "{}", self.as_str())
@@ -104,88 +177,243 @@ impl Expression {
             expr: expr.to_string(),
             post_fix: String::new(),
             result: Err(ErrorKind::InvalidExpression),
+            float_result: Err(ErrorKind::InvalidExpression),
         }
     }
 
-    /// Determines the precedence of operators to aid in postfix conversion
+    /// Determines the precedence of operators to aid in postfix conversion.
+    /// `'_'` is the internal sentinel for unary minus (see `parse_primary`); it
+    /// binds tighter than `*`/`/` but looser than `^`, so `-3^2` is `-(3^2)`.
     pub fn precedence(operator: char) -> u8 {
         match operator {
+            '&' | '|' | '~' => 0,
             '+' | '-' => 1,
             '*' | '/' => 2,
-            _ => 0, // highest precedence
+            '_' => 3,
+            '^' => 4,
+            _ => 0,
+        }
+    }
+
+    /// Determines the associativity of an operator, used by `infix_binding_power` to decide
+    /// whether a chain of equal-precedence operators groups from the left or from the right
+    pub fn associativity(operator: char) -> Associativity {
+        match operator {
+            '^' | '_' => Associativity::Right,
+            _ => Associativity::Left,
         }
     }
 
-    /// Converts the stored infix expression to postfix notation
+    /// Converts the stored infix expression to postfix notation via `parse_ast`
     pub fn infix_to_postfix(&mut self) -> Result<(), ErrorKind> {
-        let mut output_queue = VecDeque::new();
-        let mut stack = Vec::new();
-        let mut number_buffer = Vec::new();
+        let ast = self.parse_ast()?;
+        let mut tokens = Vec::new();
+        Self::flatten_postfix(&ast, &mut tokens);
+        self.post_fix = tokens.join(" ");
+        Ok(())
+    }
+
+    fn flatten_postfix(node: &Node, tokens: &mut Vec<String>) {
+        match node {
+            Node::Number(raw) => tokens.push(raw.clone()),
+            Node::BinaryOp { op, lhs, rhs } => {
+                Self::flatten_postfix(lhs, tokens);
+                Self::flatten_postfix(rhs, tokens);
+                tokens.push(op.to_string());
+            }
+            Node::UnaryOp { op, operand } => {
+                Self::flatten_postfix(operand, tokens);
+                tokens.push(if *op == '-' {
+                    String::from("u-")
+                } else {
+                    op.to_string()
+                });
+            }
+        }
+    }
+
+    /// Parses the stored infix expression into an abstract syntax tree via a Pratt parser
+    pub fn parse_ast(&self) -> Result<Node, ErrorKind> {
+        let tokens = Self::tokenize(&self.expr)?;
+        let mut cursor = TokenCursor { tokens, pos: 0 };
+        let ast = Self::parse_expr(&mut cursor, 0)?;
+
+        if cursor.peek().is_some() {
+            return Err(ErrorKind::InvalidExpression);
+        }
+
+        Ok(ast)
+    }
+
+    /// Parses an expression whose operators all bind at least as tightly as `min_bp`
+    fn parse_expr(cursor: &mut TokenCursor, min_bp: u8) -> Result<Node, ErrorKind> {
+        let mut lhs = Self::parse_primary(cursor)?;
+
+        loop {
+            match cursor.peek() {
+                Some(Token::Bang) => {
+                    if FACTORIAL_BINDING_POWER < min_bp {
+                        break;
+                    }
+                    cursor.next();
+                    lhs = Node::UnaryOp {
+                        op: '!',
+                        operand: Box::new(lhs),
+                    };
+                }
+                Some(Token::Op(op)) => {
+                    let op = *op;
+                    let (left_bp, right_bp) = Self::infix_binding_power(op);
+                    if left_bp < min_bp {
+                        break;
+                    }
+                    cursor.next();
+                    let rhs = Self::parse_expr(cursor, right_bp)?;
+                    lhs = Node::BinaryOp {
+                        op,
+                        lhs: Box::new(lhs),
+                        rhs: Box::new(rhs),
+                    };
+                }
+                _ => break,
+            }
+        }
+
+        Ok(lhs)
+    }
+
+    /// Parses a number, a parenthesized sub-expression, or a prefix `+`/`-`
+    fn parse_primary(cursor: &mut TokenCursor) -> Result<Node, ErrorKind> {
+        match cursor.next() {
+            Some(Token::Number(raw)) => Ok(Node::Number(raw)),
+            Some(Token::Op('+')) => Self::parse_expr(cursor, 2 * Self::precedence('_')),
+            Some(Token::Op('-')) => {
+                let operand = Self::parse_expr(cursor, 2 * Self::precedence('_'))?;
+                Ok(Node::UnaryOp {
+                    op: '-',
+                    operand: Box::new(operand),
+                })
+            }
+            Some(Token::LParen) => {
+                let inner = Self::parse_expr(cursor, 0)?;
+                match cursor.next() {
+                    Some(Token::RParen) => Ok(inner),
+                    _ => Err(ErrorKind::MalformedExpression),
+                }
+            }
+            _ => Err(ErrorKind::InvalidExpression),
+        }
+    }
+
+    /// Left and right binding power of an infix operator, derived from `precedence`/`associativity`
+    fn infix_binding_power(op: char) -> (u8, u8) {
+        let bp = Self::precedence(op) * 2;
+        match Self::associativity(op) {
+            Associativity::Left => (bp, bp + 1),
+            Associativity::Right => (bp, bp - 1),
+        }
+    }
 
-        let mut tokens = self.expr.chars().peekable();
+    /// Splits an infix expression into tokens, accepting hex/binary numeric literals
+    fn tokenize(expr: &str) -> Result<Vec<Token>, ErrorKind> {
+        let mut tokens = Vec::new();
+        let mut number_buffer: Vec<char> = Vec::new();
+        let mut chars = expr.chars().peekable();
 
-        while let Some(&ch) = tokens.peek() {
+        while let Some(&ch) = chars.peek() {
             match ch {
                 '0'..='9' => {
                     number_buffer.push(ch);
-                    tokens.next();
+                    chars.next();
                 }
-                '!' => {
-                    Self::flush_num_buffer(&mut number_buffer, &mut output_queue);
-                    output_queue.push_back(String::from("!"));
-                    tokens.next();
+                '.' if !number_buffer.contains(&'.') => {
+                    number_buffer.push(ch);
+                    chars.next();
+                }
+                'x' | 'X' | 'b' | 'B' if number_buffer.len() == 1 && number_buffer[0] == '0' => {
+                    // Extends a leading `0` into a `0x`/`0b` hexadecimal or binary prefix.
+                    number_buffer.push(ch);
+                    chars.next();
+                }
+                'a'..='f' | 'A'..='F'
+                    if number_buffer.len() >= 2
+                        && number_buffer[0] == '0'
+                        && matches!(number_buffer[1], 'x' | 'X') =>
+                {
+                    number_buffer.push(ch);
+                    chars.next();
                 }
                 ' ' => {
-                    Self::flush_num_buffer(&mut number_buffer, &mut output_queue);
-                    tokens.next();
+                    Self::flush_number_token(&mut number_buffer, &mut tokens);
+                    chars.next();
                 }
-                '+' | '-' | '*' | '/' => {
-                    Self::flush_num_buffer(&mut number_buffer, &mut output_queue);
-                    while let Some(&operation) = stack.last() {
-                        if operation == '(' || !Self::has_higher_precedence(operation, ch) {
-                            break;
-                        }
-                        output_queue.push_back(stack.pop().unwrap().to_string());
-                    }
-                    stack.push(ch);
-                    tokens.next();
+                '!' => {
+                    Self::flush_number_token(&mut number_buffer, &mut tokens);
+                    tokens.push(Token::Bang);
+                    chars.next();
                 }
                 '(' => {
-                    stack.push(ch);
-                    tokens.next();
+                    Self::flush_number_token(&mut number_buffer, &mut tokens);
+                    tokens.push(Token::LParen);
+                    chars.next();
                 }
                 ')' => {
-                    Self::flush_num_buffer(&mut number_buffer, &mut output_queue);
-                    while let Some(op) = stack.pop() {
-                        if op == '(' {
-                            break;
-                        }
-                        output_queue.push_back(op.to_string());
-                    }
-                    tokens.next();
+                    Self::flush_number_token(&mut number_buffer, &mut tokens);
+                    tokens.push(Token::RParen);
+                    chars.next();
+                }
+                '+' | '-' | '*' | '/' | '^' | '&' | '|' | '~' => {
+                    Self::flush_number_token(&mut number_buffer, &mut tokens);
+                    tokens.push(Token::Op(ch));
+                    chars.next();
                 }
                 _ => return Err(ErrorKind::InvalidExpression),
             }
         }
+        Self::flush_number_token(&mut number_buffer, &mut tokens);
 
-        Self::flush_num_buffer(&mut number_buffer, &mut output_queue);
+        Ok(tokens)
+    }
 
-        while let Some(op) = stack.pop() {
-            if op == '(' {
-                return Err(ErrorKind::MalformedExpression); // Handle unbalanced parentheses
-            }
-            output_queue.push_back(op.to_string());
+    fn flush_number_token(number_buffer: &mut Vec<char>, tokens: &mut Vec<Token>) {
+        if !number_buffer.is_empty() {
+            tokens.push(Token::Number(number_buffer.iter().collect()));
+            number_buffer.clear();
         }
+    }
 
-        self.post_fix = output_queue.into_iter().collect::<Vec<_>>().join(" ");
-        Ok(())
+    /// Parses an operand token, accepting plain decimal literals as well as
+    /// `0x`/`0X`-prefixed hexadecimal and `0b`/`0B`-prefixed binary literals.
+    fn parse_operand(token: &str) -> Result<BigInt, ErrorKind> {
+        if let Some(hex) = token
+            .strip_prefix("0x")
+            .or_else(|| token.strip_prefix("0X"))
+        {
+            BigInt::from_str_radix(hex, 16).map_err(|_| ErrorKind::InvalidToken)
+        } else if let Some(bin) = token
+            .strip_prefix("0b")
+            .or_else(|| token.strip_prefix("0B"))
+        {
+            BigInt::from_str_radix(bin, 2).map_err(|_| ErrorKind::InvalidToken)
+        } else {
+            token.parse::<BigInt>().map_err(|_| ErrorKind::InvalidToken)
+        }
     }
 
-    fn flush_num_buffer(number_buffer: &mut Vec<char>, output: &mut VecDeque<String>) {
-        if !number_buffer.is_empty() {
-            let num = number_buffer.iter().collect::<String>();
-            output.push_back(num);
-            number_buffer.clear();
+    /// Parses an operand token for the floating-point evaluator, accepting `0x`/`0X`
+    /// hexadecimal and `0b`/`0B` binary literals (via `parse_operand`) in addition to
+    /// plain decimal literals, which may include a fractional part.
+    fn parse_operand_f64(token: &str) -> Result<f64, ErrorKind> {
+        if token.starts_with("0x")
+            || token.starts_with("0X")
+            || token.starts_with("0b")
+            || token.starts_with("0B")
+        {
+            Self::parse_operand(token)?
+                .to_f64()
+                .ok_or(ErrorKind::Overflow)
+        } else {
+            token.parse::<f64>().map_err(|_| ErrorKind::InvalidToken)
         }
     }
 
@@ -194,96 +422,190 @@ impl Expression {
         &self.post_fix
     }
 
-    /// Checks if the first operator has higher precendence over the second
-    pub fn has_higher_precedence(op1: char, op2: char) -> bool {
-        Self::precedence(op1) > Self::precedence(op2)
+    /// Recursively evaluates an AST node as an arbitrary-precision integer.
+    fn eval_bigint(node: &Node) -> Result<BigInt, ErrorKind> {
+        match node {
+            Node::Number(raw) => Self::parse_operand(raw),
+            Node::BinaryOp { op, lhs, rhs } => {
+                let lhs = Self::eval_bigint(lhs)?;
+                let rhs = Self::eval_bigint(rhs)?;
+                match *op {
+                    '+' => Ok(lhs + rhs),
+                    '-' => Ok(lhs - rhs),
+                    '*' => Ok(lhs * rhs),
+                    '/' => {
+                        if rhs.is_zero() {
+                            return Err(ErrorKind::DivisionByZero);
+                        }
+                        Ok(lhs / rhs)
+                    }
+                    '^' => {
+                        if rhs.is_negative() {
+                            return Err(ErrorKind::NegativeExponent);
+                        }
+                        let exponent = rhs.to_u32().ok_or(ErrorKind::Overflow)?;
+                        if exponent > MAX_EXPONENT {
+                            return Err(ErrorKind::TooLarge);
+                        }
+                        Ok(num_traits::pow(lhs, exponent as usize))
+                    }
+                    '&' => Ok(lhs & rhs),
+                    '|' => Ok(lhs | rhs),
+                    '~' => Ok(lhs ^ rhs),
+                    _ => unreachable!(), // Already validated during tokenizing
+                }
+            }
+            Node::UnaryOp { op, operand } => {
+                let value = Self::eval_bigint(operand)?;
+                match *op {
+                    '-' => Ok(-value),
+                    '!' => {
+                        if value.is_negative() {
+                            return Err(ErrorKind::NegativeFactorial);
+                        }
+                        let n = value.to_usize().ok_or(ErrorKind::Overflow)?;
+                        Self::factorial(n)
+                    }
+                    _ => unreachable!(), // Already validated during tokenizing
+                }
+            }
+        }
     }
 
-    /// Evaluates the postfix expression and stores the 'result'
+    /// Evaluates the stored expression and stores the 'result'
     pub fn compute_expression(&mut self) -> Result<(), ErrorKind> {
-        let post_fix_vector: Vec<&str> = self.post_fix.split_whitespace().collect();
-        let mut stack: Vec<i64> = Vec::new();
+        let ast = self.parse_ast()?;
+        self.result = Ok(Self::eval_bigint(&ast)?);
+        Ok(())
+    }
 
-        for token in post_fix_vector.iter() {
-            match *token {
-                "+" | "-" | "*" | "/" => {
-                    if stack.len() < 2 {
-                        return Err(ErrorKind::InsufficientOperands);
-                    }
-                    let operand2 = stack.pop().unwrap();
-                    let operand1 = stack.pop().unwrap();
-
-                    let result = match *token {
-                        "+" => operand1 + operand2,
-                        "-" => operand1 - operand2,
-                        "*" => operand1 * operand2,
-                        "/" => {
-                            if operand2 == 0 {
-                                return Err(ErrorKind::DivisionByZero);
-                            }
-                            operand1 / operand2
+    /// Recursively evaluates an AST node as a floating-point number
+    fn eval_float(node: &Node) -> Result<f64, ErrorKind> {
+        match node {
+            Node::Number(raw) => Self::parse_operand_f64(raw),
+            Node::BinaryOp { op, lhs, rhs } => {
+                let lhs = Self::eval_float(lhs)?;
+                let rhs = Self::eval_float(rhs)?;
+                match *op {
+                    '+' => Ok(lhs + rhs),
+                    '-' => Ok(lhs - rhs),
+                    '*' => Ok(lhs * rhs),
+                    '/' => {
+                        if rhs == 0.0 {
+                            return Err(ErrorKind::DivisionByZero);
                         }
-                        _ => unreachable!(), // Already validated during infix to postfix conversion
-                    };
-                    stack.push(result);
-                }
-                "!" => {
-                    if let Some(a) = stack.pop() {
-                        let fact = Self::factorial(a as usize)?;
-                        stack.push(fact);
-                    } else {
-                        return Err(ErrorKind::InsufficientOperands);
+                        Ok(lhs / rhs)
                     }
+                    '^' => Ok(lhs.powf(rhs)),
+                    '&' | '|' | '~' => Err(ErrorKind::InvalidToken),
+                    _ => unreachable!(), // Already validated during tokenizing
                 }
-                _ => {
-                    if let Ok(num) = token.parse::<i64>() {
-                        stack.push(num);
-                    } else {
-                        return Err(ErrorKind::InvalidToken);
+            }
+            Node::UnaryOp { op, operand } => {
+                let value = Self::eval_float(operand)?;
+                match *op {
+                    '-' => Ok(-value),
+                    '!' => {
+                        if value.fract() != 0.0 {
+                            return Err(ErrorKind::NonIntegerFactorial);
+                        }
+                        if value < 0.0 {
+                            return Err(ErrorKind::NegativeFactorial);
+                        }
+                        let fact = Self::factorial(value as usize)?;
+                        fact.to_f64().ok_or(ErrorKind::Overflow)
                     }
+                    _ => unreachable!(), // Already validated during tokenizing
                 }
             }
         }
+    }
 
-        if stack.len() == 1 {
-            self.result = Ok(stack.pop().unwrap());
-        } else {
-            return Err(ErrorKind::MalformedExpression);
-        }
-
+    /// Evaluates the stored expression as floating-point, storing the result in
+    /// `float_result` instead of truncating division the way `compute_expression` does.
+    pub fn compute_expression_float(&mut self) -> Result<(), ErrorKind> {
+        let ast = self.parse_ast()?;
+        self.float_result = Ok(Self::eval_float(&ast)?);
         Ok(())
     }
 
     /// Computes factorial of a number, utilizing a cache to improve performance
-    fn factorial(n: usize) -> Result<i64, ErrorKind> {
+    fn factorial(n: usize) -> Result<BigInt, ErrorKind> {
+        if n > MAX_FACTORIAL_ARG {
+            return Err(ErrorKind::TooLarge);
+        }
+
         let mut cache = FACTORIAL_CACHE.lock().map_err(|_| ErrorKind::Overflow)?;
 
         if n >= cache.len() {
             for i in cache.len()..=n {
-                let last = *cache.last().unwrap();
-                match last.checked_mul(i as i64) {
-                    Some(result) => cache.push(result),
-                    None => return Err(ErrorKind::Overflow),
+                let next = cache.last().unwrap() * BigInt::from(i);
+                cache.push(next);
+            }
+        }
+
+        cache.get(n).cloned().ok_or(ErrorKind::InvalidExpression)
+    }
+
+    /// Reconstructs an infix expression from the stored postfix notation. Every
+    /// binary operator is rendered fully parenthesized (e.g. `3 4 +` becomes
+    /// `(3 + 4)`) so the result is unambiguous regardless of operator precedence.
+    pub fn postfix_to_infix(&self) -> Result<String, ErrorKind> {
+        let post_fix_vector: Vec<&str> = self.post_fix.split_whitespace().collect();
+        let mut stack: Vec<String> = Vec::new();
+
+        for token in post_fix_vector.iter() {
+            match *token {
+                "+" | "-" | "*" | "/" | "^" | "&" | "|" | "~" => {
+                    let rhs = stack.pop().ok_or(ErrorKind::MalformedExpression)?;
+                    let lhs = stack.pop().ok_or(ErrorKind::MalformedExpression)?;
+                    stack.push(format!("({} {} {})", lhs, token, rhs));
+                }
+                "!" => {
+                    let operand = stack.pop().ok_or(ErrorKind::MalformedExpression)?;
+                    stack.push(format!("({})!", operand));
+                }
+                "u-" => {
+                    let operand = stack.pop().ok_or(ErrorKind::MalformedExpression)?;
+                    stack.push(format!("(-{})", operand));
                 }
+                _ => stack.push(token.to_string()),
             }
         }
 
-        cache.get(n).copied().ok_or(ErrorKind::InvalidExpression)
+        if stack.len() == 1 {
+            Ok(stack.pop().unwrap())
+        } else {
+            Err(ErrorKind::MalformedExpression)
+        }
     }
 
     /// Returns reference to the computation 'Result<>'
-    pub fn get_result(&self) -> &Result<i64, ErrorKind> {
+    pub fn get_result(&self) -> &Result<BigInt, ErrorKind> {
         &self.result
     }
 
-    /// Process the expression by converting it to postfix and optionally computing it
-    pub fn process_expression(&mut self, compute: bool) -> Result<(), ErrorKind> {
+    /// Returns reference to the floating-point computation 'Result<>'
+    pub fn get_float_result(&self) -> &Result<f64, ErrorKind> {
+        &self.float_result
+    }
+
+    /// Process the expression by converting it to postfix and optionally computing it.
+    /// When `float` is set, evaluation goes through `compute_expression_float` instead
+    /// of the big-integer path, so results like `10/3` aren't truncated.
+    pub fn process_expression(&mut self, compute: bool, float: bool) -> Result<(), ErrorKind> {
         self.infix_to_postfix()?;
 
         if !compute {
             println!("Postfix: [{}]", self.show_post_fix());
         }
-        if compute {
+        if compute && float {
+            self.compute_expression_float()?;
+            match self.get_float_result() {
+                Ok(result) => println!("Result = {}", result),
+                Err(e) => println!("Error: {}", e),
+            }
+        } else if compute {
             self.compute_expression()?;
             match self.get_result() {
                 Ok(result) => println!("Result = {}", result),
@@ -10,7 +10,7 @@ use kalkulator::Expression;
 )]
 pub struct Args {
     /// The mathematical expression to be processed.
-    #[arg(short, long)]
+    #[arg(short, long, allow_hyphen_values = true)]
     expr: Option<String>,
 
     /// Flag to only convert the expression to postfix notation, without evaluating it.
@@ -20,6 +20,15 @@ pub struct Args {
     /// Flag to display the available operators.
     #[arg(short = 's', long = "show-ops", action = clap::ArgAction::SetTrue)]
     show_ops: bool,
+
+    /// Flag to evaluate using floating-point arithmetic instead of integer arithmetic.
+    #[arg(short = 'f', long = "float", action = clap::ArgAction::SetTrue)]
+    float: bool,
+
+    /// A postfix expression to reconstruct back into infix notation, instead of
+    /// evaluating `--expr`.
+    #[arg(short = 'i', long = "from-postfix", allow_hyphen_values = true)]
+    from_postfix: Option<String>,
 }
 
 fn main() {
@@ -40,11 +49,19 @@ fn main() {
         return; // Exit after displaying the information
     }
 
-    if let Some(expression) = args.expr {
+    if let Some(postfix) = args.from_postfix {
+        let mut obj = Expression::new("");
+        obj.post_fix = postfix.trim().to_string();
+
+        match obj.postfix_to_infix() {
+            Ok(infix) => println!("Infix: {}", infix),
+            Err(e) => eprintln!("Error reconstructing expression: {}", e.as_str()),
+        }
+    } else if let Some(expression) = args.expr {
         let trimmed_expression = expression.trim();
         let mut obj = Expression::new(trimmed_expression);
 
-        match obj.process_expression(!args.to_postfix) {
+        match obj.process_expression(!args.to_postfix, args.float) {
             Ok(_) => {} // In case of success, nothing needs to be done here.
             Err(e) => {
                 // Handle different kinds of errors with appropriate messages
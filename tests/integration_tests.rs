@@ -1,4 +1,5 @@
-use kalkulator::Expression;
+use kalkulator::{ErrorKind, Expression, Node};
+use num_bigint::BigInt;
 
 /// Tests basic arithmetic operation.
 #[test]
@@ -8,7 +9,7 @@ fn test_basic_arithmetic() {
         .expect("Failed to convert to postfix");
     expr.compute_expression()
         .expect("Failed to compute expression!");
-    assert_eq!(*expr.get_result().as_ref().unwrap(), 5.0);
+    assert_eq!(*expr.get_result().as_ref().unwrap(), BigInt::from(5));
 }
 
 /// Tests division and ensures correct handling of division by zero.
@@ -28,7 +29,7 @@ fn test_factorial_operation() {
         .expect("Failed to convert to postfix");
     expr.compute_expression()
         .expect("Failed to compute expression");
-    assert_eq!(*expr.get_result().as_ref().unwrap(), 120.0);
+    assert_eq!(*expr.get_result().as_ref().unwrap(), BigInt::from(120));
 }
 
 /// Tests complex expression combining various operations.
@@ -40,7 +41,7 @@ fn test_complex_expression() {
     expr.compute_expression()
         .expect("Failed to compute expression");
 
-    assert_eq!(*expr.get_result().as_ref().unwrap(), 7.0);
+    assert_eq!(*expr.get_result().as_ref().unwrap(), BigInt::from(7));
 }
 
 /// Tests exponentiation and factorial combined
@@ -51,5 +52,273 @@ fn test_exponentiation_factorial() {
         .expect("Failed to convert to postfix");
     expr.compute_expression()
         .expect("Failed to compute expression");
-    assert_eq!(*expr.get_result().as_ref().unwrap(), 730.0);
+    assert_eq!(*expr.get_result().as_ref().unwrap(), BigInt::from(730));
+}
+
+/// Tests that exponentiation is right-associative, so `2^3^2` is `2^(3^2)`.
+#[test]
+fn test_exponentiation_right_associative() {
+    let mut expr = Expression::new("2^3^2");
+    expr.infix_to_postfix()
+        .expect("Failed to convert to postfix");
+    expr.compute_expression()
+        .expect("Failed to compute expression");
+    assert_eq!(*expr.get_result().as_ref().unwrap(), BigInt::from(512));
+}
+
+/// Tests that raising to a negative exponent is reported as an error.
+#[test]
+fn test_negative_exponent_error() {
+    let mut expr = Expression::new("2^-1");
+    expr.post_fix = String::from("2 -1 ^");
+    let compute_result = expr.compute_expression();
+    assert!(compute_result.is_err());
+}
+
+/// Tests the bitwise AND, OR, and XOR operators together with hex and binary literals.
+#[test]
+fn test_bitwise_operators_with_hex_and_binary_literals() {
+    let mut expr = Expression::new("0xFF & 0b1010 | 3");
+    expr.infix_to_postfix()
+        .expect("Failed to convert to postfix");
+    expr.compute_expression()
+        .expect("Failed to compute expression");
+    assert_eq!(*expr.get_result().as_ref().unwrap(), BigInt::from(11));
+}
+
+/// Tests the bitwise XOR operator on its own.
+#[test]
+fn test_bitwise_xor() {
+    let mut expr = Expression::new("6~3");
+    expr.infix_to_postfix()
+        .expect("Failed to convert to postfix");
+    expr.compute_expression()
+        .expect("Failed to compute expression");
+    assert_eq!(*expr.get_result().as_ref().unwrap(), BigInt::from(5));
+}
+
+/// Tests that float mode keeps division precision instead of truncating.
+#[test]
+fn test_float_division_is_not_truncated() {
+    let mut expr = Expression::new("10/3");
+    expr.infix_to_postfix()
+        .expect("Failed to convert to postfix");
+    expr.compute_expression_float()
+        .expect("Failed to compute expression");
+    let result = expr.get_float_result().as_ref().unwrap();
+    assert!((result - 3.333_333_333_333_333_5).abs() < 1e-9);
+}
+
+/// Tests that float mode parses decimal literals.
+#[test]
+fn test_float_literal_parsing() {
+    let mut expr = Expression::new("3.5+1.5");
+    expr.infix_to_postfix()
+        .expect("Failed to convert to postfix");
+    expr.compute_expression_float()
+        .expect("Failed to compute expression");
+    assert_eq!(*expr.get_float_result().as_ref().unwrap(), 5.0);
+}
+
+/// Tests that float mode also accepts the hex/binary literals the tokenizer advertises.
+#[test]
+fn test_float_hex_and_binary_literals() {
+    let mut expr = Expression::new("0xFF + 0b10");
+    expr.infix_to_postfix()
+        .expect("Failed to convert to postfix");
+    expr.compute_expression_float()
+        .expect("Failed to compute expression");
+    assert_eq!(*expr.get_float_result().as_ref().unwrap(), 257.0);
+}
+
+/// Tests that factorial of a non-integer float is reported as an error.
+#[test]
+fn test_float_factorial_of_non_integer_errors() {
+    let mut expr = Expression::new("3.5!");
+    expr.infix_to_postfix()
+        .expect("Failed to convert to postfix");
+    let compute_result = expr.compute_expression_float();
+    assert!(compute_result.is_err());
+}
+
+/// Tests that factorials too large for `i64` are computed exactly as big integers.
+#[test]
+fn test_large_factorial_does_not_overflow() {
+    let mut expr = Expression::new("25!");
+    expr.infix_to_postfix()
+        .expect("Failed to convert to postfix");
+    expr.compute_expression()
+        .expect("Failed to compute expression");
+    let expected: BigInt = "15511210043330985984000000".parse().unwrap();
+    assert_eq!(*expr.get_result().as_ref().unwrap(), expected);
+}
+
+/// Tests that a factorial argument beyond the safety bound is rejected instead of
+/// hanging.
+#[test]
+fn test_factorial_too_large_is_rejected() {
+    let mut expr = Expression::new("50000!");
+    expr.infix_to_postfix()
+        .expect("Failed to convert to postfix");
+    let compute_result = expr.compute_expression();
+    assert_eq!(compute_result, Err(ErrorKind::TooLarge));
+}
+
+/// Tests that an exponent beyond the safety bound is rejected instead of hanging.
+#[test]
+fn test_exponent_too_large_is_rejected() {
+    let mut expr = Expression::new("2^1000000000");
+    expr.infix_to_postfix()
+        .expect("Failed to convert to postfix");
+    let compute_result = expr.compute_expression();
+    assert_eq!(compute_result, Err(ErrorKind::TooLarge));
+}
+
+/// Tests unary minus at the start of an expression.
+#[test]
+fn test_unary_minus_at_start() {
+    let mut expr = Expression::new("-3+2");
+    expr.infix_to_postfix()
+        .expect("Failed to convert to postfix");
+    expr.compute_expression()
+        .expect("Failed to compute expression");
+    assert_eq!(*expr.get_result().as_ref().unwrap(), BigInt::from(-1));
+}
+
+/// Tests unary minus immediately following another operator.
+#[test]
+fn test_unary_minus_after_operator() {
+    let mut expr = Expression::new("4*-2");
+    expr.infix_to_postfix()
+        .expect("Failed to convert to postfix");
+    expr.compute_expression()
+        .expect("Failed to compute expression");
+    assert_eq!(*expr.get_result().as_ref().unwrap(), BigInt::from(-8));
+}
+
+/// Tests unary minus applied to a parenthesized sub-expression, followed by factorial.
+#[test]
+fn test_unary_minus_with_parentheses_and_factorial() {
+    let mut expr = Expression::new("(-5)!");
+    expr.infix_to_postfix()
+        .expect("Failed to convert to postfix");
+    let compute_result = expr.compute_expression();
+    assert_eq!(compute_result, Err(ErrorKind::NegativeFactorial));
+}
+
+/// Tests that unary minus binds looser than `^`, so `-3^2` is `-(3^2)`.
+#[test]
+fn test_unary_minus_binds_looser_than_exponent() {
+    let mut expr = Expression::new("-3^2");
+    expr.infix_to_postfix()
+        .expect("Failed to convert to postfix");
+    expr.compute_expression()
+        .expect("Failed to compute expression");
+    assert_eq!(*expr.get_result().as_ref().unwrap(), BigInt::from(-9));
+}
+
+/// Tests that unary minus applies to the result of the factorial,
+/// so `-2!` is `-(2!)`.
+#[test]
+fn test_unary_minus_with_factorial() {
+    let mut expr = Expression::new("-2!");
+    expr.infix_to_postfix()
+        .expect("Failed to convert to postfix");
+    expr.compute_expression()
+        .expect("Failed to compute expression");
+    assert_eq!(*expr.get_result().as_ref().unwrap(), BigInt::from(-2));
+}
+
+/// Tests that `parse_ast` builds the expected tree shape for a simple binary expression.
+#[test]
+fn test_parse_ast_binary_op() {
+    let expr = Expression::new("3+4");
+    let ast = expr.parse_ast().expect("Failed to parse AST");
+    assert_eq!(
+        ast,
+        Node::BinaryOp {
+            op: '+',
+            lhs: Box::new(Node::Number(String::from("3"))),
+            rhs: Box::new(Node::Number(String::from("4"))),
+        }
+    );
+}
+
+/// Tests that `parse_ast` respects operator precedence, nesting `2*3` under `+`.
+#[test]
+fn test_parse_ast_respects_precedence() {
+    let expr = Expression::new("1+2*3");
+    let ast = expr.parse_ast().expect("Failed to parse AST");
+    assert_eq!(
+        ast,
+        Node::BinaryOp {
+            op: '+',
+            lhs: Box::new(Node::Number(String::from("1"))),
+            rhs: Box::new(Node::BinaryOp {
+                op: '*',
+                lhs: Box::new(Node::Number(String::from("2"))),
+                rhs: Box::new(Node::Number(String::from("3"))),
+            }),
+        }
+    );
+}
+
+/// Tests that `parse_ast` represents unary minus and factorial as `UnaryOp` nodes.
+#[test]
+fn test_parse_ast_unary_minus_and_factorial() {
+    let expr = Expression::new("-3!");
+    let ast = expr.parse_ast().expect("Failed to parse AST");
+    assert_eq!(
+        ast,
+        Node::UnaryOp {
+            op: '-',
+            operand: Box::new(Node::UnaryOp {
+                op: '!',
+                operand: Box::new(Node::Number(String::from("3"))),
+            }),
+        }
+    );
+}
+
+/// Tests that a malformed expression (unbalanced parentheses) is rejected by `parse_ast`.
+#[test]
+fn test_parse_ast_rejects_malformed_expression() {
+    let expr = Expression::new("(3+4");
+    assert!(expr.parse_ast().is_err());
+}
+
+/// Tests that `postfix_to_infix` fully parenthesizes a simple binary expression.
+#[test]
+fn test_postfix_to_infix_binary_op() {
+    let mut expr = Expression::new("3+4");
+    expr.post_fix = String::from("3 4 +");
+    assert_eq!(expr.postfix_to_infix().unwrap(), "(3 + 4)");
+}
+
+/// Tests that `postfix_to_infix` round-trips a more complex expression produced by
+/// `infix_to_postfix`.
+#[test]
+fn test_postfix_to_infix_round_trip() {
+    let mut expr = Expression::new("3+4*2");
+    expr.infix_to_postfix()
+        .expect("Failed to convert to postfix");
+    assert_eq!(expr.postfix_to_infix().unwrap(), "(3 + (4 * 2))");
+}
+
+/// Tests that `postfix_to_infix` reconstructs unary minus and factorial correctly.
+#[test]
+fn test_postfix_to_infix_unary_minus_and_factorial() {
+    let mut expr = Expression::new("-3!");
+    expr.infix_to_postfix()
+        .expect("Failed to convert to postfix");
+    assert_eq!(expr.postfix_to_infix().unwrap(), "(-(3)!)");
+}
+
+/// Tests that `postfix_to_infix` reports a malformed expression when the postfix
+/// token stream doesn't reduce to exactly one value.
+#[test]
+fn test_postfix_to_infix_rejects_malformed_postfix() {
+    let mut expr = Expression::new("");
+    expr.post_fix = String::from("3 4");
+    assert!(expr.postfix_to_infix().is_err());
 }